@@ -0,0 +1,140 @@
+//! Adapters between [`crate::Mutex`] and the [`mutex-traits`](https://docs.rs/mutex-traits)
+//! ecosystem's [`ScopedRawMutex`].
+//!
+//! `maitake-sync` and other `mutex-traits`-generic libraries are written against
+//! [`ScopedRawMutex`], a closure-based raw lock that knows nothing about the data it guards.
+//! RTIC resources, on the other hand, are handed out as `impl Mutex<T = ...>` values that already
+//! own their data. The two adapters in this module let values cross that boundary without
+//! hand-written newtypes:
+//!
+//! - [`AsScopedRawMutex`] wraps any [`Mutex`] so it can drive a `ScopedRawMutex`-based container,
+//!   such as `maitake-sync`'s `Mutex` or `RwLock`.
+//! - [`ScopedMutex`] wraps a [`ScopedRawMutex`] together with the data it guards so the pair
+//!   satisfies [`Mutex`].
+use core::cell::{RefCell, UnsafeCell};
+
+use critical_section::Mutex as CsMutex;
+use mutex_traits::ScopedRawMutex;
+
+use crate::Mutex;
+
+/// Adapts any [`Mutex`] into a [`ScopedRawMutex`]
+///
+/// The wrapped resource is accessed through a [`critical_section::Mutex`], the same interior
+/// mutability `ScopedRawMutex`'s own implementors use, so the adapter is `Sync` and can be shared
+/// across tasks and interrupts (typically behind a `static`), exactly as `ScopedRawMutex`-based
+/// containers like `maitake-sync`'s `Mutex` or `RwLock` expect.
+pub struct AsScopedRawMutex<M>(CsMutex<RefCell<M>>);
+
+impl<M> AsScopedRawMutex<M> {
+    /// Wraps `mutex` so it can be used as a [`ScopedRawMutex`]
+    pub const fn new(mutex: M) -> Self {
+        AsScopedRawMutex(CsMutex::new(RefCell::new(mutex)))
+    }
+
+    /// Unwraps the adapter, returning the original mutex
+    pub fn into_inner(self) -> M {
+        self.0.into_inner().into_inner()
+    }
+}
+
+// SAFETY: `try_borrow_mut`/`borrow_mut` on the wrapped `RefCell` fail (respectively panic) if the
+// adapter is already locked, so no two calls to `with_lock`/`try_with_lock` can ever be in
+// progress at the same time, upholding `ScopedRawMutex`'s exclusivity invariant.
+unsafe impl<M> ScopedRawMutex for AsScopedRawMutex<M>
+where
+    M: Mutex,
+{
+    fn try_with_lock<R>(&self, f: impl FnOnce() -> R) -> Option<R> {
+        critical_section::with(|cs| {
+            self.0
+                .borrow(cs)
+                .try_borrow_mut()
+                .ok()
+                .map(|mut mutex| mutex.lock(|_| f()))
+        })
+    }
+
+    fn with_lock<R>(&self, f: impl FnOnce() -> R) -> R {
+        critical_section::with(|cs| self.0.borrow(cs).borrow_mut().lock(|_| f()))
+    }
+
+    fn is_locked(&self) -> bool {
+        critical_section::with(|cs| self.0.borrow(cs).try_borrow().is_err())
+    }
+}
+
+/// Adapts a [`ScopedRawMutex`] and the data it guards into a [`Mutex`]
+///
+/// This is the inverse of [`AsScopedRawMutex`]: it lets a raw lock from the `mutex-traits`
+/// ecosystem (or any crate generic over [`ScopedRawMutex`]) stand in for an RTIC resource.
+pub struct ScopedMutex<L, T> {
+    raw: L,
+    data: UnsafeCell<T>,
+}
+
+impl<L, T> ScopedMutex<L, T> {
+    /// Pairs a raw lock with the data it guards
+    pub const fn new(raw: L, data: T) -> Self {
+        ScopedMutex {
+            raw,
+            data: UnsafeCell::new(data),
+        }
+    }
+}
+
+// SAFETY: `raw` serializes every access to `data`, so it is sound to share a `ScopedMutex` across
+// contexts as long as the raw lock itself is `Sync` and the data is `Send`.
+unsafe impl<L, T> Sync for ScopedMutex<L, T>
+where
+    L: ScopedRawMutex + Sync,
+    T: Send,
+{
+}
+
+impl<L, T> Mutex for ScopedMutex<L, T>
+where
+    L: ScopedRawMutex,
+{
+    type T = T;
+
+    fn lock<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        self.raw.with_lock(|| {
+            // SAFETY: `with_lock` grants exclusive access to `data` for the duration of the
+            // closure, and `&mut self` already rules out any other live reference to `Self`.
+            let data = unsafe { &mut *self.data.get() };
+            f(data)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Exclusive;
+
+    #[test]
+    fn scoped_mutex_locks_through_as_scoped_raw_mutex() {
+        let mut state = 0;
+        let raw = AsScopedRawMutex::new(Exclusive(&mut state));
+        let mut scoped = ScopedMutex::new(raw, 41);
+
+        let result = scoped.lock(|data| {
+            *data += 1;
+            *data
+        });
+
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn as_scoped_raw_mutex_rejects_reentrant_try_with_lock() {
+        let mut state = 0;
+        let raw = AsScopedRawMutex::new(Exclusive(&mut state));
+
+        let reentrant_attempt = raw.with_lock(|| raw.try_with_lock(|| ()));
+
+        assert!(reentrant_attempt.is_none());
+        assert!(!raw.is_locked());
+    }
+}
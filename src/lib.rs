@@ -22,6 +22,9 @@
 
 use core::ops;
 
+#[cfg(feature = "mutex-traits")]
+pub mod interop;
+
 /// Memory safe access to shared resources
 ///
 /// In RTIC, locks are implemented as critical sections that prevent other tasks from *starting*.
@@ -34,6 +37,65 @@ pub trait Mutex {
 
     /// Creates a critical section and grants temporary access to the protected data
     fn lock<R>(&mut self, f: impl FnOnce(&mut Self::T) -> R) -> R;
+
+    /// Attempts to create a critical section and grants temporary access to the protected data,
+    /// returning `None` instead of deadlocking if the resource is already locked
+    ///
+    /// The default implementation always succeeds by forwarding to [`Mutex::lock`]. Backends
+    /// for strictly single-threaded or non-reentrant platforms, where entering `lock` while it is
+    /// already held is always a programming error rather than something to wait on, should
+    /// override this to detect that reentrant entry and return `None` instead of deadlocking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rtic_core::{Exclusive, Mutex};
+    ///
+    /// fn try_lock(a: &mut impl Mutex<T = i32>) -> Option<i32> {
+    ///     a.try_lock(|a| {
+    ///         *a += 1;
+    ///         *a
+    ///     })
+    /// }
+    ///
+    /// let mut data = 0;
+    /// assert_eq!(try_lock(&mut Exclusive(&mut data)), Some(1));
+    /// ```
+    fn try_lock<R>(&mut self, f: impl FnOnce(&mut Self::T) -> R) -> Option<R> {
+        Some(self.lock(f))
+    }
+
+    /// Narrows this mutex into one that only exposes a projection of its data
+    ///
+    /// The projection closure runs inside the same critical section as the parent's `lock`, so
+    /// `map` adds no locking of its own; it lets a library that only needs `impl Mutex<T = U>`
+    /// accept a resource whose real type is some larger `T` that contains a `U`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rtic_core::{Exclusive, Mutex};
+    ///
+    /// struct Registers {
+    ///     value: i32,
+    /// }
+    ///
+    /// fn bump_value(registers: impl Mutex<T = i32>) {
+    ///     let mut registers = registers;
+    ///     registers.lock(|value| *value += 1);
+    /// }
+    ///
+    /// let mut registers = Registers { value: 0 };
+    /// bump_value(Exclusive(&mut registers).map(|r| &mut r.value));
+    /// assert_eq!(registers.value, 1);
+    /// ```
+    fn map<U, F>(self, f: F) -> MapMutex<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut Self::T) -> &mut U,
+    {
+        MapMutex { mutex: self, f }
+    }
 }
 
 impl<'a, M> Mutex for &'a mut M
@@ -45,6 +107,10 @@ where
     fn lock<R>(&mut self, f: impl FnOnce(&mut M::T) -> R) -> R {
         M::lock(self, f)
     }
+
+    fn try_lock<R>(&mut self, f: impl FnOnce(&mut M::T) -> R) -> Option<R> {
+        M::try_lock(self, f)
+    }
 }
 
 /// Newtype over `&'a mut T` that implements the `Mutex` trait
@@ -74,8 +140,83 @@ impl<'a, T> ops::DerefMut for Exclusive<'a, T> {
     }
 }
 
-/// Makes locks work on N-tuples, locks the mutexes from left-to-right in the tuple. These are
-/// used to reduce rightward drift in code and to help make intentions clearer.
+/// A [`Mutex`] that exposes a projection of another [`Mutex`]'s data
+///
+/// Created by [`Mutex::map`]; see that method for details.
+pub struct MapMutex<M, F> {
+    mutex: M,
+    f: F,
+}
+
+impl<M, F, U> Mutex for MapMutex<M, F>
+where
+    M: Mutex,
+    F: FnMut(&mut M::T) -> &mut U,
+{
+    type T = U;
+
+    fn lock<R>(&mut self, f: impl FnOnce(&mut U) -> R) -> R {
+        let project = &mut self.f;
+        self.mutex.lock(|data| f(project(data)))
+    }
+}
+
+/// Memory safe, read/write access to shared resources
+///
+/// Many resources are written by a single task but only ever read by the others. Routing every
+/// access through [`Mutex::lock`] forces even read-only tasks to pay for the full ceiling, even
+/// though a narrower *reader* ceiling (the maximum priority of the tasks that only read the
+/// resource) would be enough to keep them safe. `RwMutex` gives backends a place to implement
+/// that split while still producing the same bounded-time critical sections as `Mutex`.
+///
+/// `read` takes `&self`, not `&mut self`, so that backends can let multiple readers genuinely
+/// hold access at once (mirroring `spin::RwLock`); only `write` needs unique access. That means
+/// there is no blanket implementation for every [`Mutex`]: `Mutex::lock` itself requires
+/// `&mut self`, so it cannot back a `&self` `read` without interior mutability. Backends that want
+/// the cheaper reader ceiling implement `RwMutex` directly against their own interior-mutable
+/// storage; [`Exclusive`] is the one example in this crate, and since it never locks at all,
+/// `read` is as trivial as `write`.
+///
+/// # Example
+///
+/// ```
+/// use rtic_core::{Exclusive, RwMutex};
+///
+/// let mut data = 1;
+/// let mut resource = Exclusive(&mut data);
+///
+/// let seen = resource.read(|data| *data);
+/// resource.write(|data| *data += 1);
+///
+/// assert_eq!(seen, 1);
+/// assert_eq!(*resource, 2);
+/// ```
+pub trait RwMutex {
+    /// Data protected by the mutex
+    type T;
+
+    /// Creates a critical section, possibly at a cheaper reader ceiling, and grants temporary
+    /// read-only access to the protected data
+    fn read<R>(&self, f: impl FnOnce(&Self::T) -> R) -> R;
+
+    /// Creates a critical section and grants temporary read-write access to the protected data
+    fn write<R>(&mut self, f: impl FnOnce(&mut Self::T) -> R) -> R;
+}
+
+impl<'a, T> RwMutex for Exclusive<'a, T> {
+    type T = T;
+
+    fn read<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(self.0)
+    }
+
+    fn write<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        self.lock(f)
+    }
+}
+
+/// Makes locks work on N-tuples (and [`Cons`] chains), locking the mutexes from left-to-right.
+/// These are used to reduce rightward drift in code and to help make intentions clearer.
 ///
 /// # Example
 ///
@@ -121,127 +262,259 @@ impl<'a, T> ops::DerefMut for Exclusive<'a, T> {
 pub mod prelude {
     pub use crate::Mutex;
 
-    macro_rules! lock {
-        ($e:ident, $fun:block) => {
-            $e.lock(|$e| $fun )
+    /// The empty end of a [`Cons`] chain.
+    pub struct Nil;
+
+    /// Cons-cell pairing one resource with the (possibly further nested) rest of the chain.
+    ///
+    /// This is the single recursive mechanism the flat tuple impls below flatten into: locking a
+    /// `Cons` locks `Head`, then hands back the still-unlocked `Tail` so the rest of the chain can
+    /// be locked the same way. Chains built this way have no arity limit, unlike the tuple impls,
+    /// which stop at 32 elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rtic_core::prelude::{Cons, Nil};
+    /// use rtic_core::Mutex;
+    ///
+    /// fn cons_lock(
+    ///     a: &mut impl Mutex<T = i32>,
+    ///     b: &mut impl Mutex<T = i32>,
+    ///     c: &mut impl Mutex<T = i32>,
+    /// ) {
+    ///     Cons(a, Cons(b, Cons(c, Nil))).lock(|a, rest| {
+    ///         rest.lock(|b, rest| {
+    ///             rest.lock(|c, _nil| {
+    ///                 *a += 1;
+    ///                 *b += 1;
+    ///                 *c += 1;
+    ///             })
+    ///         })
+    ///     });
+    /// }
+    /// ```
+    pub struct Cons<Head, Tail>(pub Head, pub Tail);
+
+    impl<Head, Tail> Cons<Head, Tail>
+    where
+        Head: crate::Mutex,
+    {
+        /// Locks the head of the chain and grants access to it together with the still-unlocked
+        /// tail, so the rest of the chain can be locked the same way.
+        pub fn lock<R>(&mut self, f: impl FnOnce(&mut Head::T, &mut Tail) -> R) -> R {
+            let Cons(head, tail) = self;
+            head.lock(|head| f(head, tail))
+        }
+
+        /// Attempts to lock the head of the chain, short-circuiting to `None` instead of
+        /// recursing into the tail if it fails.
+        pub fn try_lock<R>(&mut self, f: impl FnOnce(&mut Head::T, &mut Tail) -> R) -> Option<R> {
+            let Cons(head, tail) = self;
+            head.try_lock(|head| f(head, tail))
+        }
+    }
+
+    // Builds the `Cons` chain for a list of resources, terminated by `Nil`.
+    macro_rules! cons_of {
+        () => { crate::prelude::Nil };
+        ($head:expr $(, $tail:expr)*) => {
+            crate::prelude::Cons($head, cons_of!($($tail),*))
+        };
+    }
+
+    // Locks a `Cons` chain one level at a time via `Cons::lock`, the same recursive mechanism
+    // `Cons` itself uses, and hands every element to `$fun` at once once the last one is reached.
+    macro_rules! cons_lock {
+        ($cons:expr, $fun:block, $head:ident) => {
+            $cons.lock(|$head, _nil| $fun)
+        };
+        ($cons:expr, $fun:block, $head:ident $(, $tail:ident)+) => {
+            $cons.lock(|$head, rest| cons_lock!(rest, $fun, $($tail),+))
+        };
+    }
+
+    // As `cons_lock!`, but through `Cons::try_lock`, short-circuiting to `None` and releasing any
+    // already-taken locks as soon as one of them fails to lock.
+    macro_rules! cons_try_lock {
+        ($cons:expr, $fun:block, $head:ident) => {
+            $cons.try_lock(|$head, _nil| $fun)
         };
-        ($e:ident, $($es:ident),+, $fun:block) => {
-            $e.lock(|$e| lock!($($es),*, $fun))
+        ($cons:expr, $fun:block, $head:ident $(, $tail:ident)+) => {
+            $cons.try_lock(|$head, rest| cons_try_lock!(rest, $fun, $($tail),+)).flatten()
         };
     }
 
+    // Every arity gets its own private module so each one can reuse the same trait name,
+    // `TupleExt`, without clashing with the others; `pub use ... as _` below re-exports just the
+    // trait's methods, never the name itself, so `prelude::*` brings `.lock()`/`.try_lock()` into
+    // scope for every tuple without growing the crate's public surface by one trait per arity.
+    //
+    // Tuples of two or more elements are pure forwarding sugar over [`Cons`]: each impl flattens
+    // its tuple into a `Cons` chain and locks it one level at a time through `Cons::lock`, so the
+    // actual locking order lives in exactly one place. Native tuples still top out at 32 elements
+    // — stable Rust has no variadic tuples to generate an impl for every arity — but `Cons` chains
+    // built and locked by hand have no such ceiling.
     macro_rules! make_tuple_impl {
-        ($name:ident, $($es:ident),+) => {
-            /// Auto-generated tuple implementation, see [`Mutex`](../trait.Mutex.html) for details.
-            pub trait $name {
-                $(
-                    /// Data protected by the mutex.
-                    type $es;
-                )*
-
-                /// Creates a critical section and grants temporary access to the protected data.
-                fn lock<R>(&mut self, f: impl FnOnce($(&mut Self::$es),*) -> R) -> R;
+        // A single-element tuple hands its closure the bare `&mut` reference rather than a
+        // one-element tuple, so `(a,).lock(|a| ...)` reads the same as locking `a` directly.
+        ($modname:ident; $only:ident) => {
+            #[allow(non_snake_case, missing_docs)]
+            mod $modname {
+                pub trait TupleExt {
+                    type T;
+
+                    fn lock<R>(&mut self, f: impl FnOnce(&mut Self::T) -> R) -> R;
+
+                    fn try_lock<R>(&mut self, f: impl FnOnce(&mut Self::T) -> R) -> Option<R>;
+                }
+
+                impl<$only> TupleExt for ($only,)
+                where
+                    $only: crate::Mutex,
+                {
+                    type T = $only::T;
+
+                    fn lock<R>(&mut self, f: impl FnOnce(&mut Self::T) -> R) -> R {
+                        self.0.lock(f)
+                    }
+
+                    fn try_lock<R>(&mut self, f: impl FnOnce(&mut Self::T) -> R) -> Option<R> {
+                        self.0.try_lock(f)
+                    }
+                }
             }
+            pub use $modname::TupleExt as _;
+        };
+        ($modname:ident; $($es:ident),+) => {
+            #[allow(non_snake_case, missing_docs)]
+            mod $modname {
+                pub trait TupleExt {
+                    $(
+                        type $es;
+                    )*
+
+                    fn lock<R>(&mut self, f: impl FnOnce($(&mut Self::$es),*) -> R) -> R;
 
-            impl<$($es),+> $name for ($($es,)+)
-            where
-                $($es: crate::Mutex),*
-            {
-                $(
-                    type $es = $es::T;
-                )*
-
-                #[allow(non_snake_case)]
-                fn lock<R>(&mut self, f: impl FnOnce($(&mut Self::$es),*) -> R) -> R {
-                    let ($(
-                        $es,
-                    )*) = self;
-
-                    lock!($($es),*, { f($($es),*) })
+                    fn try_lock<R>(&mut self, f: impl FnOnce($(&mut Self::$es),*) -> R) -> Option<R>;
+                }
+
+                impl<$($es),+> TupleExt for ($($es,)+)
+                where
+                    $($es: crate::Mutex),*
+                {
+                    $(
+                        type $es = $es::T;
+                    )*
+
+                    #[allow(non_snake_case)]
+                    fn lock<R>(&mut self, f: impl FnOnce($(&mut Self::$es),*) -> R) -> R {
+                        let ($($es,)*) = self;
+                        cons_lock!(cons_of!($($es),*), { f($($es),*) }, $($es),*)
+                    }
+
+                    #[allow(non_snake_case)]
+                    fn try_lock<R>(&mut self, f: impl FnOnce($(&mut Self::$es),*) -> R) -> Option<R> {
+                        let ($($es,)*) = self;
+                        cons_try_lock!(cons_of!($($es),*), { f($($es),*) }, $($es),*)
+                    }
                 }
             }
+            pub use $modname::TupleExt as _;
         };
     }
 
-    // Generate tuple lock impls
-    make_tuple_impl!(TupleExt01, T1);
-    make_tuple_impl!(TupleExt02, T1, T2);
-    make_tuple_impl!(TupleExt03, T1, T2, T3);
-    make_tuple_impl!(TupleExt04, T1, T2, T3, T4);
-    make_tuple_impl!(TupleExt05, T1, T2, T3, T4, T5);
-    make_tuple_impl!(TupleExt06, T1, T2, T3, T4, T5, T6);
-    make_tuple_impl!(TupleExt07, T1, T2, T3, T4, T5, T6, T7);
-    make_tuple_impl!(TupleExt08, T1, T2, T3, T4, T5, T6, T7, T8);
-    make_tuple_impl!(TupleExt09, T1, T2, T3, T4, T5, T6, T7, T8, T9);
-    make_tuple_impl!(TupleExt10, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
-    make_tuple_impl!(TupleExt11, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
-    make_tuple_impl!(TupleExt12, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
-    make_tuple_impl!(TupleExt13, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13);
-    make_tuple_impl!(TupleExt14, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14);
-    make_tuple_impl!(TupleExt15, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15);
-    make_tuple_impl!(
-        TupleExt16, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16
-    );
-    make_tuple_impl!(
-        TupleExt17, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17
-    );
-    make_tuple_impl!(
-        TupleExt18, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17, T18
-    );
-    make_tuple_impl!(
-        TupleExt19, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19
-    );
-    make_tuple_impl!(
-        TupleExt20, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20
-    );
-    make_tuple_impl!(
-        TupleExt21, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21
-    );
-    make_tuple_impl!(
-        TupleExt22, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22
-    );
-    make_tuple_impl!(
-        TupleExt23, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23
-    );
-    make_tuple_impl!(
-        TupleExt24, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23, T24
-    );
-    make_tuple_impl!(
-        TupleExt25, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23, T24, T25
-    );
-    make_tuple_impl!(
-        TupleExt26, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23, T24, T25, T26
-    );
-    make_tuple_impl!(
-        TupleExt27, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23, T24, T25, T26, T27
-    );
-    make_tuple_impl!(
-        TupleExt28, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23, T24, T25, T26, T27, T28
-    );
-    make_tuple_impl!(
-        TupleExt29, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23, T24, T25, T26, T27, T28, T29
-    );
-    make_tuple_impl!(
-        TupleExt30, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23, T24, T25, T26, T27, T28, T29, T30
-    );
-    make_tuple_impl!(
-        TupleExt31, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23, T24, T25, T26, T27, T28, T29, T30, T31
-    );
-    make_tuple_impl!(
-        TupleExt32, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17,
-        T18, T19, T20, T21, T22, T23, T24, T25, T26, T27, T28, T29, T30, T31, T32
+    // Peels one identifier off the front of the list on every step, implementing `TupleExt` for
+    // the remaining suffix each time and reusing that identifier as the (otherwise arbitrary)
+    // module name. A single invocation below this definition therefore covers every arity from 32
+    // elements down to 1, instead of 32 hand-written invocations.
+    macro_rules! make_tuple_impls {
+        () => {};
+        ($head:ident $(, $tail:ident)*) => {
+            make_tuple_impl!($head; $head $(, $tail)*);
+            make_tuple_impls!($($tail),*);
+        };
+    }
+
+    make_tuple_impls!(
+        T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17, T18, T19, T20,
+        T21, T22, T23, T24, T25, T26, T27, T28, T29, T30, T31, T32
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    /// A [`Mutex`] whose `try_lock` can be told to fail without ever calling `f`, for exercising
+    /// the real override contract that [`Mutex::try_lock`]'s default implementation can't.
+    struct FallibleMutex<T> {
+        data: T,
+        fails: bool,
+        locked: Cell<bool>,
+    }
+
+    impl<T> FallibleMutex<T> {
+        fn new(data: T, fails: bool) -> Self {
+            FallibleMutex {
+                data,
+                fails,
+                locked: Cell::new(false),
+            }
+        }
+    }
+
+    impl<T> Mutex for FallibleMutex<T> {
+        type T = T;
+
+        fn lock<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+            self.locked.set(true);
+            let result = f(&mut self.data);
+            self.locked.set(false);
+            result
+        }
+
+        fn try_lock<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+            if self.fails {
+                None
+            } else {
+                Some(self.lock(f))
+            }
+        }
+    }
+
+    #[test]
+    fn try_lock_override_fails_without_calling_f() {
+        let mut mutex = FallibleMutex::new(0, true);
+
+        let result = mutex.try_lock(|data| {
+            *data += 1;
+            *data
+        });
+
+        assert_eq!(result, None);
+        assert_eq!(mutex.data, 0);
+    }
+
+    #[test]
+    fn tuple_try_lock_short_circuits_on_first_failure() {
+        use crate::prelude::*;
+
+        let mut a = FallibleMutex::new(1, false);
+        let mut b = FallibleMutex::new(2, true);
+        let mut c = FallibleMutex::new(3, false);
+
+        let result = (&mut a, &mut b, &mut c).try_lock(|a, b, c| {
+            *a += 1;
+            *b += 1;
+            *c += 1;
+        });
+
+        assert!(result.is_none());
+        assert_eq!(a.data, 1, "a's lock must be released, not left holding its pre-failure value");
+        assert!(!a.locked.get(), "a must be unlocked again once b's try_lock fails");
+        assert_eq!(c.data, 3, "c must never be reached once b's try_lock fails");
+        assert!(!c.locked.get());
+    }
+}